@@ -0,0 +1,154 @@
+use regex::Regex;
+
+/// SQL 静态检查的严重级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warn,
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warn => "warn",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// 单条检查结果
+pub struct Advice {
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// 一条规则：正则 + 命中后的提示信息 + 严重级别
+struct Rule {
+    pattern: Regex,
+    severity: Severity,
+    message: &'static str,
+}
+
+/// 构建内置规则集。数据驱动，新增规则只需追加一项。
+///
+/// `regex` 不支持回顾/预查断言，"没有 WHERE/LIMIT" 这类否定条件改由
+/// `analyze_statement` 里的纯关键字判断处理，不属于这里的正则规则。
+fn rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            pattern: Regex::new(r"(?i)select\s+\*").unwrap(),
+            severity: Severity::Warn,
+            message: "使用了 SELECT *，建议显式列出所需字段",
+        },
+        Rule {
+            pattern: Regex::new(r"(?i)like\s+'%").unwrap(),
+            severity: Severity::Warn,
+            message: "LIKE 以通配符开头，无法使用索引",
+        },
+        Rule {
+            pattern: Regex::new(r#"(?i)\w+\s*=\s*'[^']*'"#).unwrap(),
+            severity: Severity::Info,
+            message: "字符串与列比较时请确认列类型一致，避免隐式类型转换",
+        },
+        Rule {
+            pattern: Regex::new(r"(?i)(!=|<>)").unwrap(),
+            severity: Severity::Info,
+            message: "对可能带索引的列使用了 != / <>，该条件通常无法使用索引",
+        },
+    ]
+}
+
+fn starts_with_keyword(lower_stmt: &str, keyword: &str) -> bool {
+    lower_stmt.trim_start().starts_with(keyword)
+}
+
+/// 把字符串字面量内容挖空，避免字面量里出现的关键字片段（如 'moved elsewhere'
+/// 中的 "where"）被误判成真正的 SQL 关键字
+fn blank_out_string_literals(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' || c == '"' {
+            let quote = c;
+            out.push(' ');
+            while let Some(nc) = chars.next() {
+                if nc == '\\' {
+                    chars.next();
+                    continue;
+                }
+                if nc == quote {
+                    break;
+                }
+                out.push(' ');
+            }
+            out.push(' ');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// 按单词边界判断关键字是否出现在语句中（字面量内容已挖空），
+/// 避免 "elsewhere" 之类包含关键字子串的普通单词/字面量造成误判
+fn contains_keyword(cleaned_lower: &str, keyword: &str) -> bool {
+    Regex::new(&format!(r"(?i)\b{keyword}\b"))
+        .unwrap()
+        .is_match(cleaned_lower)
+}
+
+/// 对单条 SQL 语句执行所有静态规则检查，返回命中的建议列表
+pub fn analyze_statement(stmt: &str) -> Vec<Advice> {
+    let stmt = stmt.trim();
+    let mut advices = Vec::new();
+    if stmt.is_empty() {
+        return advices;
+    }
+
+    for rule in rules() {
+        if rule.pattern.is_match(stmt) {
+            advices.push(Advice {
+                severity: rule.severity,
+                message: rule.message.to_string(),
+            });
+        }
+    }
+
+    // 纯关键字判断，避免依赖 regex 不支持的否定预查断言；
+    // 字符串字面量先挖空，再按单词边界匹配，避免 "elsewhere" 这类
+    // 字面量里包含关键字子串的语句被误判为已有 WHERE/LIMIT
+    let lower = stmt.to_lowercase();
+    let cleaned = blank_out_string_literals(&lower);
+    let has_where = contains_keyword(&cleaned, "where");
+    let has_limit = contains_keyword(&cleaned, "limit");
+    let has_order_by = Regex::new(r"(?i)\border\s+by\b").unwrap().is_match(&cleaned);
+
+    if starts_with_keyword(&lower, "select") && !has_where {
+        advices.push(Advice {
+            severity: Severity::Warn,
+            message: "SELECT 语句缺少 WHERE 条件，可能导致全表扫描".to_string(),
+        });
+    }
+    if starts_with_keyword(&lower, "update") && !has_where {
+        advices.push(Advice {
+            severity: Severity::Error,
+            message: "UPDATE 语句缺少 WHERE 条件，将修改全表数据".to_string(),
+        });
+    }
+    if starts_with_keyword(&lower, "delete") && !has_where {
+        advices.push(Advice {
+            severity: Severity::Error,
+            message: "DELETE 语句缺少 WHERE 条件，将删除全表数据".to_string(),
+        });
+    }
+    if has_order_by && !has_limit {
+        advices.push(Advice {
+            severity: Severity::Info,
+            message: "ORDER BY 未配合 LIMIT 使用，可能返回过多数据".to_string(),
+        });
+    }
+
+    advices
+}
@@ -0,0 +1,60 @@
+use mysql_async::{Conn, Error, IoError, Pool};
+use rand::Rng;
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const BASE_DELAY: Duration = Duration::from_millis(500);
+const MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 判断一个 mysql_async 错误是否是值得重试的瞬时网络错误。
+/// 鉴权失败、库不存在等错误视为永久性错误，直接失败。
+fn is_transient(err: &Error) -> bool {
+    match err {
+        Error::Io(IoError::Io(io_err)) => matches!(
+            io_err.kind(),
+            io::ErrorKind::ConnectionRefused
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+        ),
+        _ => false,
+    }
+}
+
+/// 取 `[0, interval)` 内的随机时长，避免多个客户端同时重连打满服务端（full jitter）。
+fn jittered_delay(interval: Duration) -> Duration {
+    let millis = interval.as_millis().max(1) as u64;
+    let jittered = rand::thread_rng().gen_range(0..millis);
+    Duration::from_millis(jittered)
+}
+
+/// 带指数退避 + 全抖动的连接获取，`create`/`validate`/`execute_sql` 三种模式共用。
+///
+/// 对 `ConnectionRefused`/`ConnectionReset`/`ConnectionAborted` 这类瞬时网络错误重试，
+/// 基础延迟翻倍递增直到 `MAX_INTERVAL`，超过 `overall_timeout` 或 `max_retries` 后放弃。
+/// 鉴权失败、未知库等永久性错误立即返回，不做重试。
+pub async fn connect_with_retry(
+    pool: &Pool,
+    max_retries: u32,
+    overall_timeout: Duration,
+) -> Result<Conn, Error> {
+    let deadline = Instant::now() + overall_timeout;
+    let mut interval = BASE_DELAY;
+    let mut attempt = 0u32;
+
+    loop {
+        match pool.get_conn().await {
+            Ok(conn) => return Ok(conn),
+            Err(err) if attempt < max_retries && is_transient(&err) && Instant::now() < deadline => {
+                attempt += 1;
+                let delay = jittered_delay(interval);
+                println!(
+                    "连接失败（第{attempt}次重试，{delay:?}后重试）：{err}"
+                );
+                sleep(delay).await;
+                interval = (interval * 2).min(MAX_INTERVAL);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/// 列类型所属的语义大类，用于判断两个字符串不同的 `COLUMN_TYPE` 是否实际兼容
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeClass {
+    Integer,
+    Decimal,
+    CharText,
+    DateTime,
+    Blob,
+    EnumSet,
+    Other,
+}
+
+/// 两个列类型的比较结果
+pub enum TypeCompat {
+    /// 字符串完全相同
+    Same,
+    /// 同一大类下的非收窄变更（如宽度变大、int 升级为 bigint）
+    Compatible,
+    /// 大类不同，或属于收窄变更
+    Incompatible,
+}
+
+/// 解析后的 `COLUMN_TYPE`，形如 `BASE(params) [unsigned] [zerofill]`
+struct ParsedType {
+    base: String,
+    width: Option<u64>,
+    scale: Option<u64>,
+    unsigned: bool,
+}
+
+fn parse_col_type(col_type: &str) -> ParsedType {
+    let lower = col_type.trim().to_lowercase();
+    let unsigned = lower.contains("unsigned");
+
+    let base = lower
+        .split(|c: char| c == '(' || c.is_whitespace())
+        .next()
+        .unwrap_or(&lower)
+        .to_string();
+
+    let (width, scale) = match (lower.find('('), lower.find(')')) {
+        (Some(start), Some(end)) if end > start => {
+            let params = &lower[start + 1..end];
+            let mut parts = params.split(',').map(|p| p.trim().parse::<u64>().ok());
+            (parts.next().flatten(), parts.next().flatten())
+        }
+        _ => (None, None),
+    };
+
+    ParsedType {
+        base,
+        width,
+        scale,
+        unsigned,
+    }
+}
+
+/// 按 `COLUMN_TYPE` 的基础类型名判断所属语义大类
+pub fn classify_type(col_type: &str) -> TypeClass {
+    let parsed = parse_col_type(col_type);
+    match parsed.base.as_str() {
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "bigint" | "bit" => {
+            TypeClass::Integer
+        }
+        "decimal" | "numeric" | "float" | "double" | "real" => TypeClass::Decimal,
+        "char" | "varchar" | "text" | "tinytext" | "mediumtext" | "longtext" => {
+            TypeClass::CharText
+        }
+        "date" | "datetime" | "timestamp" | "time" | "year" => TypeClass::DateTime,
+        "binary" | "varbinary" | "blob" | "tinyblob" | "mediumblob" | "longblob" => {
+            TypeClass::Blob
+        }
+        "enum" | "set" => TypeClass::EnumSet,
+        _ => TypeClass::Other,
+    }
+}
+
+// 按体积从小到大排列，用于判断整数类型是否被“升级”
+const INT_RANK: [&str; 6] = ["tinyint", "smallint", "mediumint", "int", "integer", "bigint"];
+const TEXT_RANK: [&str; 6] = ["char", "varchar", "tinytext", "text", "mediumtext", "longtext"];
+
+fn int_rank(base: &str) -> Option<usize> {
+    INT_RANK.iter().position(|b| *b == base)
+}
+
+fn text_rank(base: &str) -> Option<usize> {
+    TEXT_RANK.iter().position(|b| *b == base)
+}
+
+/// 判断 `old` 到 `new` 是否是兼容变更。
+/// `strict` 为 true 时只认字符串完全相等，不做任何类宽松匹配。
+pub fn types_compatible(old: &str, new: &str, strict: bool) -> TypeCompat {
+    if old == new {
+        return TypeCompat::Same;
+    }
+    if strict {
+        return TypeCompat::Incompatible;
+    }
+
+    let old_class = classify_type(old);
+    let new_class = classify_type(new);
+    if old_class != new_class {
+        return TypeCompat::Incompatible;
+    }
+
+    let old_parsed = parse_col_type(old);
+    let new_parsed = parse_col_type(new);
+
+    match old_class {
+        TypeClass::Integer => {
+            // unsigned 变化本身不截断已有数据（除非同时伴随类型收窄），
+            // 按兼容处理，而不是直接判失败（例如 int -> int unsigned）
+            if old_parsed.base == new_parsed.base {
+                // 同一类型，仅显示宽度变大，如 int(10) -> int(11)
+                match (old_parsed.width, new_parsed.width) {
+                    (Some(o), Some(n)) if n >= o => TypeCompat::Compatible,
+                    (None, _) => TypeCompat::Compatible,
+                    _ => TypeCompat::Incompatible,
+                }
+            } else {
+                match (int_rank(&old_parsed.base), int_rank(&new_parsed.base)) {
+                    (Some(o), Some(n)) if n > o => TypeCompat::Compatible,
+                    _ => TypeCompat::Incompatible,
+                }
+            }
+        }
+        TypeClass::CharText => {
+            if old_parsed.base == new_parsed.base {
+                match (old_parsed.width, new_parsed.width) {
+                    (Some(o), Some(n)) if n >= o => TypeCompat::Compatible,
+                    (None, _) => TypeCompat::Compatible,
+                    _ => TypeCompat::Incompatible,
+                }
+            } else {
+                // 类型升级的同时长度不能收窄，如 char(255) -> varchar(10)、varchar(300) -> tinytext
+                let width_ok = match (old_parsed.width, new_parsed.width) {
+                    (Some(o), Some(n)) => n >= o,
+                    (Some(o), None) => new_parsed.base != "tinytext" || o <= 255,
+                    _ => true,
+                };
+                match (text_rank(&old_parsed.base), text_rank(&new_parsed.base)) {
+                    (Some(o), Some(n)) if n > o && width_ok => TypeCompat::Compatible,
+                    _ => TypeCompat::Incompatible,
+                }
+            }
+        }
+        TypeClass::Decimal => {
+            if old_parsed.base != new_parsed.base {
+                return TypeCompat::Incompatible;
+            }
+            match (
+                old_parsed.width,
+                new_parsed.width,
+                old_parsed.scale,
+                new_parsed.scale,
+            ) {
+                (Some(ow), Some(nw), os, ns) if nw >= ow && ns == os => TypeCompat::Compatible,
+                _ => TypeCompat::Incompatible,
+            }
+        }
+        // 日期时间/二进制/枚举集合等暂不做宽松匹配，差异一律视为不兼容
+        TypeClass::DateTime | TypeClass::Blob | TypeClass::EnumSet | TypeClass::Other => {
+            TypeCompat::Incompatible
+        }
+    }
+}